@@ -0,0 +1,214 @@
+use core::mem::MaybeUninit;
+
+use crate::ArrayPlusExtra;
+
+/// A fixed-size buffer of `M` elements that are initialized incrementally from the front.
+///
+/// Dropping a `PartialArray` before it is [`finish`](PartialArray::finish)ed drops exactly
+/// the elements that were pushed, so a panicking or error-returning initializer never
+/// leaks or double-drops elements.
+struct PartialArray<T, const M: usize> {
+    buf: [MaybeUninit<T>; M],
+    len: usize,
+}
+
+impl<T, const M: usize> PartialArray<T, M> {
+    #[inline]
+    fn new() -> Self {
+        Self {
+            buf: [const { MaybeUninit::uninit() }; M],
+            len: 0,
+        }
+    }
+
+    /// Push the next element. Panics if the buffer is already full.
+    #[inline]
+    fn push(&mut self, value: T) {
+        self.buf[self.len] = MaybeUninit::new(value);
+        self.len += 1;
+    }
+
+    /// Consume the buffer. All `M` slots must have been initialized; this is checked only
+    /// in debug builds (via `debug_assert_eq!`).
+    #[inline]
+    fn finish(self) -> [T; M] {
+        debug_assert_eq!(self.len, M);
+        // SAFETY: `len == M` means every slot of `buf` has been initialized, and
+        // `MaybeUninit<T>` has the same layout as `T`. We forget `self` so the now-moved-out
+        // elements are not also dropped by `PartialArray`'s `Drop` impl.
+        let array =
+            unsafe { core::ptr::read(&self.buf as *const [MaybeUninit<T>; M] as *const [T; M]) };
+        core::mem::forget(self);
+        array
+    }
+}
+
+impl<T, const M: usize> Drop for PartialArray<T, M> {
+    fn drop(&mut self) {
+        for slot in &mut self.buf[..self.len] {
+            // SAFETY: the first `len` slots have been initialized by `push` and not yet
+            // moved out by `finish`.
+            unsafe { slot.assume_init_drop() };
+        }
+    }
+}
+
+/// Error returned by [`ArrayPlusExtra::try_from_iter`] when the iterator does not yield
+/// exactly `N+EXTRA` elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryFromIterError {
+    /// The iterator yielded fewer than `N+EXTRA` elements.
+    TooFew,
+    /// The iterator yielded more than `N+EXTRA` elements.
+    TooMany,
+}
+
+impl core::fmt::Display for TryFromIterError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TooFew => f.write_str("iterator yielded fewer elements than N+EXTRA"),
+            Self::TooMany => f.write_str("iterator yielded more elements than N+EXTRA"),
+        }
+    }
+}
+
+impl<T, const N: usize, const EXTRA: usize> ArrayPlusExtra<T, N, EXTRA> {
+    /// Create a new array by calling `f` with each index in `0..N+EXTRA`, in order.
+    #[inline]
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        match Self::try_from_fn(|i| Ok::<T, core::convert::Infallible>(f(i))) {
+            Ok(this) => this,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Create a new array by calling `f` with each index in `0..N+EXTRA`, in order,
+    /// short-circuiting on the first error.
+    ///
+    /// Elements already produced before the error (or a panic from `f`) are dropped; none
+    /// are leaked or double-dropped.
+    pub fn try_from_fn<F, E>(mut f: F) -> Result<Self, E>
+    where
+        F: FnMut(usize) -> Result<T, E>,
+    {
+        let mut data = PartialArray::<T, N>::new();
+        for i in 0..N {
+            data.push(f(i)?);
+        }
+
+        let mut extra = PartialArray::<T, EXTRA>::new();
+        for i in 0..EXTRA {
+            extra.push(f(N + i)?);
+        }
+
+        Ok(Self {
+            data: data.finish(),
+            extra: extra.finish(),
+        })
+    }
+
+    /// Create a new array by pulling exactly `N+EXTRA` items from `iter`, in order.
+    ///
+    /// Returns [`TryFromIterError::TooFew`] if the iterator is exhausted early, or
+    /// [`TryFromIterError::TooMany`] if it yields at least one more item than needed.
+    /// Elements already pulled before such an error are dropped; none are leaked or
+    /// double-dropped.
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, TryFromIterError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+
+        let mut data = PartialArray::<T, N>::new();
+        for _ in 0..N {
+            data.push(iter.next().ok_or(TryFromIterError::TooFew)?);
+        }
+
+        let mut extra = PartialArray::<T, EXTRA>::new();
+        for _ in 0..EXTRA {
+            extra.push(iter.next().ok_or(TryFromIterError::TooFew)?);
+        }
+
+        if iter.next().is_some() {
+            return Err(TryFromIterError::TooMany);
+        }
+
+        Ok(Self {
+            data: data.finish(),
+            extra: extra.finish(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_from_fn_indices() {
+        let arr: ArrayPlusExtra<usize, 3, 2> = ArrayPlusExtra::from_fn(|i| i * 10);
+        assert_eq!(arr.as_slice(), &[0, 10, 20, 30, 40]);
+    }
+
+    #[test]
+    fn test_try_from_fn_ok() {
+        let arr: Result<ArrayPlusExtra<i32, 2, 2>, &str> =
+            ArrayPlusExtra::try_from_fn(|i| Ok(i as i32));
+        assert_eq!(arr.unwrap().as_slice(), &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_try_from_fn_propagates_error() {
+        let result: Result<ArrayPlusExtra<i32, 2, 2>, &str> = ArrayPlusExtra::try_from_fn(|i| {
+            if i == 3 { Err("boom") } else { Ok(i as i32) }
+        });
+        assert_eq!(result, Err("boom"));
+    }
+
+    #[test]
+    fn test_try_from_fn_drops_elements_built_before_error() {
+        let counter = Cell::new(0);
+        struct DropCounter<'a>(&'a Cell<usize>);
+        impl Drop for DropCounter<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let result: Result<ArrayPlusExtra<DropCounter, 2, 2>, &str> =
+            ArrayPlusExtra::try_from_fn(|i| {
+                if i == 3 {
+                    Err("boom")
+                } else {
+                    Ok(DropCounter(&counter))
+                }
+            });
+        assert!(result.is_err());
+        // The 3 elements built before the error are dropped, and no more.
+        assert_eq!(counter.get(), 3);
+    }
+
+    #[test]
+    fn test_try_from_iter_exact() {
+        let arr: Result<ArrayPlusExtra<i32, 2, 3>, _> =
+            ArrayPlusExtra::try_from_iter([1, 2, 3, 4, 5]);
+        assert_eq!(arr.unwrap().as_slice(), &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_try_from_iter_too_few() {
+        let result: Result<ArrayPlusExtra<i32, 2, 3>, _> = ArrayPlusExtra::try_from_iter([1, 2, 3]);
+        assert_eq!(result, Err(TryFromIterError::TooFew));
+    }
+
+    #[test]
+    fn test_try_from_iter_too_many() {
+        let result: Result<ArrayPlusExtra<i32, 2, 3>, _> =
+            ArrayPlusExtra::try_from_iter([1, 2, 3, 4, 5, 6]);
+        assert_eq!(result, Err(TryFromIterError::TooMany));
+    }
+}
@@ -0,0 +1,237 @@
+use core::mem::MaybeUninit;
+
+use crate::ArrayPlusExtra;
+
+/// An owned, by-value iterator over the `N+EXTRA` elements of an [`ArrayPlusExtra`].
+///
+/// Created by the [`IntoIterator`] impl on [`ArrayPlusExtra`] (e.g. via a `for` loop).
+/// Elements are yielded in order, and any elements that have not yet been yielded are
+/// dropped when the iterator itself is dropped.
+pub struct IntoIter<T, const N: usize, const EXTRA: usize> {
+    data: [MaybeUninit<T>; N],
+    extra: [MaybeUninit<T>; EXTRA],
+    // Indices into the logical `[0, N+EXTRA)` range that have not yet been yielded.
+    front: usize,
+    back: usize,
+}
+
+impl<T, const N: usize, const EXTRA: usize> IntoIter<T, N, EXTRA> {
+    /// Read the element at `index` out of `data`/`extra` without checking bounds.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be one of the not-yet-yielded indices (the `[front, back)` range as it
+    /// was before the caller moved either cursor past it) and must not have been read out
+    /// before.
+    #[inline]
+    unsafe fn take_unchecked(&mut self, index: usize) -> T {
+        if index < N {
+            unsafe { self.data[index].assume_init_read() }
+        } else {
+            unsafe { self.extra[index - N].assume_init_read() }
+        }
+    }
+}
+
+impl<T, const N: usize, const EXTRA: usize> Iterator for IntoIter<T, N, EXTRA> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        // SAFETY: `front` is in `[front, back)` and has not been read out before.
+        let value = unsafe { self.take_unchecked(self.front) };
+        self.front += 1;
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize, const EXTRA: usize> DoubleEndedIterator for IntoIter<T, N, EXTRA> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        // SAFETY: the decremented `back` was the last index of `[front, back)` before the
+        // decrement and has not been read out before.
+        Some(unsafe { self.take_unchecked(self.back) })
+    }
+}
+
+impl<T, const N: usize, const EXTRA: usize> ExactSizeIterator for IntoIter<T, N, EXTRA> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+impl<T, const N: usize, const EXTRA: usize> Drop for IntoIter<T, N, EXTRA> {
+    fn drop(&mut self) {
+        for index in self.front..self.back {
+            // SAFETY: every index in `[front, back)` has not been read out before.
+            unsafe { self.take_unchecked(index) };
+        }
+    }
+}
+
+impl<T, const N: usize, const EXTRA: usize> IntoIterator for ArrayPlusExtra<T, N, EXTRA> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N, EXTRA>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        // SAFETY: `MaybeUninit<T>` has the same layout as `T`, so reading `data`/`extra`
+        // through a `[MaybeUninit<T>; _]`-typed pointer is valid. `self` is forgotten
+        // immediately after so its fields are not dropped a second time; ownership of
+        // every element is transferred to the returned `IntoIter`.
+        let data =
+            unsafe { core::ptr::read(&self.data as *const [T; N] as *const [MaybeUninit<T>; N]) };
+        let extra = unsafe {
+            core::ptr::read(&self.extra as *const [T; EXTRA] as *const [MaybeUninit<T>; EXTRA])
+        };
+        core::mem::forget(self);
+
+        IntoIter {
+            data,
+            extra,
+            front: 0,
+            back: N + EXTRA,
+        }
+    }
+}
+
+impl<'a, T, const N: usize, const EXTRA: usize> IntoIterator for &'a ArrayPlusExtra<T, N, EXTRA> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+impl<'a, T, const N: usize, const EXTRA: usize> IntoIterator
+    for &'a mut ArrayPlusExtra<T, N, EXTRA>
+{
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_into_iter_order_and_len() {
+        let mut arr: ArrayPlusExtra<i32, 3, 2> = ArrayPlusExtra::new(0);
+        arr[0] = 10;
+        arr[1] = 20;
+        arr[2] = 30;
+        arr[3] = 40;
+        arr[4] = 50;
+
+        let mut iter = arr.into_iter();
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.next(), Some(10));
+        assert_eq!(iter.next(), Some(20));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back(), Some(50));
+        assert_eq!(iter.next_back(), Some(40));
+        assert_eq!(iter.next(), Some(30));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_into_iter_for_loop() {
+        let arr: ArrayPlusExtra<i32, 2, 2> = ArrayPlusExtra::new(7);
+        let mut sum = 0;
+        for value in arr {
+            sum += value;
+        }
+        assert_eq!(sum, 28);
+    }
+
+    #[test]
+    fn test_ref_into_iter() {
+        let arr: ArrayPlusExtra<i32, 2, 1> = ArrayPlusExtra::new(3);
+        let sum: i32 = (&arr).into_iter().sum();
+        assert_eq!(sum, 9);
+        // Original is still usable since we only borrowed it.
+        assert_eq!(arr.len(), 3);
+    }
+
+    #[test]
+    fn test_mut_ref_into_iter() {
+        let mut arr: ArrayPlusExtra<i32, 2, 1> = ArrayPlusExtra::new(1);
+        for value in &mut arr {
+            *value += 1;
+        }
+        assert_eq!(arr[0], 2);
+        assert_eq!(arr[2], 2);
+    }
+
+    // A `Drop`-counting type, similar to the drop-counter test helper used by
+    // `generic-array`, to prove partial consumption drops exactly the remaining
+    // elements once and never double-drops or leaks.
+    struct DropCounter<'a>(&'a Cell<usize>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_into_iter_drops_remaining_elements_only() {
+        let counter = Cell::new(0);
+        // Private-field construction is available here since `iter` is a descendant
+        // module of the crate root that defines `ArrayPlusExtra`.
+        let arr: ArrayPlusExtra<DropCounter, 3, 2> = ArrayPlusExtra {
+            data: [
+                DropCounter(&counter),
+                DropCounter(&counter),
+                DropCounter(&counter),
+            ],
+            extra: [DropCounter(&counter), DropCounter(&counter)],
+        };
+
+        let mut iter = arr.into_iter();
+        // Yield two of the five elements, then drop the iterator.
+        drop(iter.next());
+        drop(iter.next());
+        assert_eq!(counter.get(), 2);
+
+        drop(iter);
+        assert_eq!(counter.get(), 5);
+    }
+
+    #[test]
+    fn test_into_iter_fully_consumed_drops_once() {
+        let counter = Cell::new(0);
+        let arr: ArrayPlusExtra<DropCounter, 2, 2> = ArrayPlusExtra {
+            data: [DropCounter(&counter), DropCounter(&counter)],
+            extra: [DropCounter(&counter), DropCounter(&counter)],
+        };
+
+        for value in arr {
+            drop(value);
+        }
+        assert_eq!(counter.get(), 4);
+    }
+}
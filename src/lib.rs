@@ -1,8 +1,12 @@
 #![no_std]
 #![doc = include_str!("../README.md")]
 
+use core::borrow::Borrow;
+use core::borrow::BorrowMut;
 use core::ops::Deref;
 use core::ops::DerefMut;
+use core::ops::Index;
+use core::ops::IndexMut;
 
 // Serde support (optional feature).
 #[cfg(feature = "serde")]
@@ -12,9 +16,19 @@ mod serde_impl;
 #[cfg(feature = "defmt")]
 mod defmt_impl;
 
+// Owned and borrowing iteration support.
+mod iter;
+
+pub use iter::IntoIter;
+
+// Fallible/closure-driven constructors.
+mod ctor;
+
+pub use ctor::TryFromIterError;
+
 /// An array that holds N+EXTRA elements, where N and EXTRA is specified via const generic.
 #[repr(C)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug)]
 #[cfg_attr(
     feature = "postcard_max_size",
     derive(postcard::experimental::max_size::MaxSize)
@@ -38,6 +52,34 @@ where
     }
 }
 
+impl<T, const N: usize, const EXTRA: usize> ArrayPlusExtra<T, N, EXTRA>
+where
+    T: Clone,
+{
+    /// Create a new array by cloning `value` into every slot.
+    ///
+    /// Unlike [`new`](Self::new), this only requires `T: Clone`, so it also works for
+    /// heap-backed or otherwise non-`Copy` payloads.
+    #[inline]
+    pub fn new_clone(value: &T) -> Self {
+        Self::from_fn(|_| value.clone())
+    }
+}
+
+// Hand-written Clone: clones element-by-element so non-`Copy` `T` is supported, rather than
+// relying on `#[derive(Clone)]`.
+impl<T, const N: usize, const EXTRA: usize> Clone for ArrayPlusExtra<T, N, EXTRA>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::from_fn(|i| self.as_slice()[i].clone())
+    }
+}
+
+// `Copy` is only available when `T: Copy`, so non-`Copy` `T` still works for everything else.
+impl<T, const N: usize, const EXTRA: usize> Copy for ArrayPlusExtra<T, N, EXTRA> where T: Copy {}
+
 impl<T, const N: usize, const EXTRA: usize> ArrayPlusExtra<T, N, EXTRA> {
     /// Convert to an array of size `M`. This checks at compile time that `M == N + EXTRA`.
     #[inline]
@@ -59,6 +101,61 @@ impl<T, const N: usize, const EXTRA: usize> ArrayPlusExtra<T, N, EXTRA> {
         this
     }
 
+    /// Build a new array from a `data` half and an `extra` half directly, without going
+    /// through `MaybeUninit`.
+    #[inline]
+    pub const fn from_parts(data: [T; N], extra: [T; EXTRA]) -> Self {
+        Self { data, extra }
+    }
+
+    /// Join a base `[T; N]` array with a separately-supplied `[T; EXTRA]` tail.
+    ///
+    /// This is an alias of [`from_parts`](Self::from_parts) for callers who think of this
+    /// type as "base array plus scratch/tail" rather than as one flat slice.
+    #[inline]
+    pub const fn concat(data: [T; N], extra: [T; EXTRA]) -> Self {
+        Self::from_parts(data, extra)
+    }
+
+    /// Split into the owned `data` and `extra` halves.
+    #[inline]
+    pub const fn into_parts(self) -> ([T; N], [T; EXTRA]) {
+        // SAFETY: `self` is forgotten immediately after reading both fields out, so they
+        // are not dropped a second time.
+        let parts = unsafe {
+            (
+                core::ptr::read(&self.data as *const [T; N]),
+                core::ptr::read(&self.extra as *const [T; EXTRA]),
+            )
+        };
+        core::mem::forget(self);
+        parts
+    }
+
+    /// Get a slice view of just the `data` half (the first `N` elements).
+    #[inline]
+    pub const fn data_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Get a mutable slice view of just the `data` half (the first `N` elements).
+    #[inline]
+    pub const fn data_slice_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Get a slice view of just the `extra` half (the trailing `EXTRA` elements).
+    #[inline]
+    pub const fn extra_slice(&self) -> &[T] {
+        &self.extra
+    }
+
+    /// Get a mutable slice view of just the `extra` half (the trailing `EXTRA` elements).
+    #[inline]
+    pub const fn extra_slice_mut(&mut self) -> &mut [T] {
+        &mut self.extra
+    }
+
     /// Get a slice view of all N+EXTRA elements.
     /// This is a const fn that can be used in const contexts.
     #[inline]
@@ -119,6 +216,118 @@ where
     }
 }
 
+// Forward PartialOrd to slice implementation.
+impl<T, const N: usize, const EXTRA: usize> PartialOrd for ArrayPlusExtra<T, N, EXTRA>
+where
+    T: PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self[..].partial_cmp(&other[..])
+    }
+}
+
+// Forward Ord to slice implementation.
+impl<T, const N: usize, const EXTRA: usize> Ord for ArrayPlusExtra<T, N, EXTRA>
+where
+    T: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self[..].cmp(&other[..])
+    }
+}
+
+// Forward Borrow/BorrowMut and AsRef/AsMut to slice implementation, so instances can key
+// `BTreeMap`/`HashMap` and be passed to slice-consuming APIs.
+impl<T, const N: usize, const EXTRA: usize> Borrow<[T]> for ArrayPlusExtra<T, N, EXTRA> {
+    #[inline]
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize, const EXTRA: usize> BorrowMut<[T]> for ArrayPlusExtra<T, N, EXTRA> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize, const EXTRA: usize> AsRef<[T]> for ArrayPlusExtra<T, N, EXTRA> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize, const EXTRA: usize> AsMut<[T]> for ArrayPlusExtra<T, N, EXTRA> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+// Forward Index/IndexMut to slice implementation, accepting any index type the standard
+// slice indexing machinery supports (single indices as well as ranges).
+impl<T, const N: usize, const EXTRA: usize, Idx> Index<Idx> for ArrayPlusExtra<T, N, EXTRA>
+where
+    Idx: core::slice::SliceIndex<[T]>,
+{
+    type Output = Idx::Output;
+
+    #[inline]
+    fn index(&self, index: Idx) -> &Self::Output {
+        Index::index(self.as_slice(), index)
+    }
+}
+
+impl<T, const N: usize, const EXTRA: usize, Idx> IndexMut<Idx> for ArrayPlusExtra<T, N, EXTRA>
+where
+    Idx: core::slice::SliceIndex<[T]>,
+{
+    #[inline]
+    fn index_mut(&mut self, index: Idx) -> &mut Self::Output {
+        IndexMut::index_mut(self.as_mut_slice(), index)
+    }
+}
+
+/// Error returned by `TryFrom<&[T]>` when the slice length does not equal `N+EXTRA`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromSliceError {
+    expected: usize,
+    actual: usize,
+}
+
+impl core::fmt::Display for TryFromSliceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "expected a slice of length {}, got length {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+// Checked conversion from a runtime slice into the fixed-size type, analogous to
+// `GenericArray::clone_from_slice`.
+impl<T, const N: usize, const EXTRA: usize> TryFrom<&[T]> for ArrayPlusExtra<T, N, EXTRA>
+where
+    T: Clone,
+{
+    type Error = TryFromSliceError;
+
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        if slice.len() != N + EXTRA {
+            return Err(TryFromSliceError {
+                expected: N + EXTRA,
+                actual: slice.len(),
+            });
+        }
+        Ok(Self::from_fn(|i| slice[i].clone()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -370,6 +579,59 @@ mod tests {
         assert_eq!(copied[0], 10);
     }
 
+    #[test]
+    fn test_new_clone_non_copy() {
+        // `String` is not `Copy`, so this only compiles thanks to `new_clone`.
+        let arr: ArrayPlusExtra<std::string::String, 2, 1> =
+            ArrayPlusExtra::new_clone(&std::string::String::from("hi"));
+        assert_eq!(arr.len(), 3);
+        assert_eq!(arr[0], "hi");
+        assert_eq!(arr[2], "hi");
+    }
+
+    // A `Drop`-counting type, as used by `generic-array`'s drop tests, to confirm clones
+    // and drops of non-`Copy` elements happen exactly `N+EXTRA` times.
+    struct DropCounter<'a>(&'a core::cell::Cell<usize>);
+
+    impl Clone for DropCounter<'_> {
+        fn clone(&self) -> Self {
+            Self(self.0)
+        }
+    }
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_new_clone_drops_exactly_n_plus_extra_times() {
+        let counter = core::cell::Cell::new(0);
+        let source = DropCounter(&counter);
+        {
+            let arr: ArrayPlusExtra<DropCounter, 2, 3> = ArrayPlusExtra::new_clone(&source);
+            assert_eq!(arr.len(), 5);
+        }
+        // The 5 clones are dropped; `source` itself is still alive.
+        assert_eq!(counter.get(), 5);
+    }
+
+    #[test]
+    fn test_clone_drops_exactly_n_plus_extra_times() {
+        let counter = core::cell::Cell::new(0);
+        let source = DropCounter(&counter);
+        let arr: ArrayPlusExtra<DropCounter, 2, 1> = ArrayPlusExtra::new_clone(&source);
+        {
+            let cloned = arr.clone();
+            assert_eq!(cloned.len(), 3);
+        }
+        // The 3 elements of `cloned` are dropped; `arr`'s own elements are not.
+        assert_eq!(counter.get(), 3);
+        drop(arr);
+        assert_eq!(counter.get(), 6);
+    }
+
     // Tests for const fn methods.
     #[test]
     fn test_as_slice_const_fn() {
@@ -452,4 +714,81 @@ mod tests {
         assert_eq!(array[0], 10);
         assert_eq!(array[4], 50);
     }
+
+    #[test]
+    fn test_from_parts_and_into_parts() {
+        let arr: ArrayPlusExtra<i32, 2, 3> = ArrayPlusExtra::from_parts([1, 2], [3, 4, 5]);
+        assert_eq!(arr.as_slice(), &[1, 2, 3, 4, 5]);
+
+        let (data, extra) = arr.into_parts();
+        assert_eq!(data, [1, 2]);
+        assert_eq!(extra, [3, 4, 5]);
+    }
+
+    #[test]
+    fn test_concat_matches_from_parts() {
+        let arr: ArrayPlusExtra<i32, 2, 3> = ArrayPlusExtra::concat([1, 2], [3, 4, 5]);
+        assert_eq!(arr.as_slice(), &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_data_and_extra_slices() {
+        let mut arr: ArrayPlusExtra<i32, 2, 3> = ArrayPlusExtra::new(0);
+        assert_eq!(arr.data_slice(), &[0, 0]);
+        assert_eq!(arr.extra_slice(), &[0, 0, 0]);
+
+        arr.data_slice_mut()[1] = 20;
+        arr.extra_slice_mut()[0] = 30;
+        assert_eq!(arr.data_slice(), &[0, 20]);
+        assert_eq!(arr.extra_slice(), &[30, 0, 0]);
+    }
+
+    #[test]
+    fn test_ord() {
+        let small: ArrayPlusExtra<i32, 2, 1> = ArrayPlusExtra::from_fn(|i| i as i32);
+        let large: ArrayPlusExtra<i32, 2, 1> = ArrayPlusExtra::new(9);
+
+        assert!(small < large);
+        assert_eq!(small.cmp(&small), core::cmp::Ordering::Equal);
+        assert_eq!(small.partial_cmp(&large), Some(core::cmp::Ordering::Less));
+    }
+
+    #[test]
+    fn test_borrow_and_as_ref() {
+        use std::collections::BTreeMap;
+
+        let arr: ArrayPlusExtra<i32, 2, 1> = ArrayPlusExtra::new(1);
+        let mut map: BTreeMap<ArrayPlusExtra<i32, 2, 1>, &str> = BTreeMap::new();
+        map.insert(arr, "one");
+        assert_eq!(map.get(&arr), Some(&"one"));
+
+        fn wants_slice(s: &[i32]) -> i32 {
+            s.iter().sum()
+        }
+        assert_eq!(wants_slice(arr.as_ref()), 3);
+    }
+
+    #[test]
+    fn test_index_range() {
+        let mut arr: ArrayPlusExtra<i32, 2, 3> = ArrayPlusExtra::from_fn(|i| i as i32);
+        assert_eq!(&arr[1..3], &[1, 2]);
+        arr[1..3].copy_from_slice(&[10, 20]);
+        assert_eq!(arr.as_slice(), &[0, 10, 20, 3, 4]);
+    }
+
+    #[test]
+    fn test_try_from_slice() {
+        let values = [1, 2, 3, 4, 5];
+        let arr = ArrayPlusExtra::<i32, 2, 3>::try_from(&values[..]).unwrap();
+        assert_eq!(arr.as_slice(), &values);
+
+        let err = ArrayPlusExtra::<i32, 2, 3>::try_from(&values[..4]).unwrap_err();
+        assert_eq!(
+            err,
+            TryFromSliceError {
+                expected: 5,
+                actual: 4,
+            }
+        );
+    }
 }
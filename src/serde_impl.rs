@@ -4,6 +4,7 @@ use crate::ArrayPlusExtra;
 use serde::{
     Deserialize, Deserializer, Serialize, Serializer,
     de::{Error, SeqAccess, Visitor},
+    ser::SerializeTuple,
 };
 
 impl<T, const N: usize, const EXTRA: usize> Serialize for ArrayPlusExtra<T, N, EXTRA>
@@ -14,7 +15,13 @@ where
     where
         S: Serializer,
     {
-        self.as_slice().serialize(serializer)
+        // `serialize_tuple` (rather than `serialize_seq`) so formats like `bincode`/`postcard`
+        // encode this the same way as a plain `[T; N+EXTRA]`, i.e. without a length prefix.
+        let mut tuple = serializer.serialize_tuple(N + EXTRA)?;
+        for elem in self.as_slice() {
+            tuple.serialize_element(elem)?;
+        }
+        tuple.end()
     }
 }
 
@@ -26,7 +33,9 @@ where
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_seq(ArrayVisitor(core::marker::PhantomData))
+        // `deserialize_tuple` mirrors the tuple hint used in `Serialize`. Self-describing
+        // formats still end up calling `visit_seq` below, so `ArrayVisitor` is unchanged.
+        deserializer.deserialize_tuple(N + EXTRA, ArrayVisitor(core::marker::PhantomData))
     }
 }
 
@@ -159,6 +168,21 @@ mod tests {
         assert_eq!(json, "[7,8,9]");
     }
 
+    #[cfg(feature = "postcard_max_size")]
+    #[test]
+    fn test_postcard_tuple_encoding_matches_plain_array() {
+        let arr: ArrayPlusExtra<u8, 2, 1> = ArrayPlusExtra::new(7);
+
+        let mut buf = [0u8; 3];
+        let encoded = postcard::to_slice(&arr, &mut buf).unwrap();
+        // A tuple-style encoding carries no length prefix, so this is byte-identical to
+        // serializing a plain `[u8; 3]`.
+        assert_eq!(encoded, &[7, 7, 7]);
+
+        let decoded: ArrayPlusExtra<u8, 2, 1> = postcard::from_bytes(encoded).unwrap();
+        assert_eq!(arr, decoded);
+    }
+
     #[test]
     fn test_roundtrip_with_different_types() {
         // Test with u8.